@@ -1,27 +1,256 @@
 //! Generates highlighted HTML with CSS classes for a Rust, using syntect and markdown
 //! Run with ```cargo run --bin make_html```
-use std::fs::read_to_string;
 use pulldown_cmark::Parser;
+use std::collections::{HashMap, HashSet};
+use std::fs::read_to_string;
 use syntect::highlighting::ThemeSet;
 use syntect::html::css_for_theme_with_class_style;
 use syntect::html::{ClassStyle, ClassedHTMLGenerator};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{ParseState, Scope, ScopeStackOp, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-fn output_code_block(ss: &SyntaxSet, mut html: impl Write, src_text: &str) -> Result<(), std::io::Error> {
-    let sr_rs = ss.find_syntax_by_extension("rs").unwrap();
+// One rendered page, as listed in `main` and threaded through every page's nav bar.
+struct Page {
+    src: String,
+    out: String,
+    title: String,
+}
+
+// Every rendered page hands back the `//-` headings it found and the items (fns, including
+// methods, and types) it defines, so `main` can assemble a single, site-wide `search_index.json` once all pages
+// are done, instead of each page only knowing about itself.
+struct PageIndex {
+    sections: Vec<(String, String)>,
+    symbols: Vec<(String, &'static str, String)>,
+}
+
+// where a defined symbol lives, keyed by name: (file, anchor)
+type SymbolMap = HashMap<String, (String, String)>;
+
+// turns a heading like "Type Definitions" into the anchor "type-definitions"
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    let mut last_dash = false;
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+            last_dash = false;
+        } else if !last_dash && !slug.is_empty() {
+            slug.push('-');
+            last_dash = true;
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    if slug.is_empty() {
+        slug.push_str("section");
+    }
+    slug
+}
+
+// appends a numeric suffix if the slug was already used earlier on the same page
+fn unique_anchor(base: &str, seen: &mut HashSet<String>) -> String {
+    let mut anchor = base.to_string();
+    let mut n = 2;
+    while !seen.insert(anchor.clone()) {
+        anchor = format!("{}-{}", base, n);
+        n += 1;
+    }
+    anchor
+}
+
+// Walks a chunk of Rust source with syntect's parser, the same way `output_code_block` does
+// for highlighting, but keeping only the scope stack instead of building HTML. A token is
+// recorded as a definition when a scope on its stack (checked innermost first) is
+// `entity.name.function` (a `fn` name)
+// or `entity.name.type`/`entity.name.struct` (a `struct`, `enum`, `trait`, or `impl` target),
+// the same convention rustdoc's search index leans on to tell definitions from mere uses.
+fn scan_definitions(ss: &SyntaxSet, src_text: &str) -> Vec<(String, &'static str)> {
+    let syntax = ss.find_syntax_by_extension("rs").unwrap();
+    let mut parse_state = ParseState::new(syntax);
+    let mut stack: Vec<Scope> = Vec::new();
+    let mut found = Vec::new();
+
+    for line in LinesWithEndings::from(src_text) {
+        let ops = match parse_state.parse_line(line, ss) {
+            Ok(ops) => ops,
+            Err(_) => continue,
+        };
+        let mut pos = 0;
+        for (idx, op) in &ops {
+            if *idx > pos {
+                record_definition(&stack, &line[pos..*idx], &mut found);
+                pos = *idx;
+            }
+            match op {
+                ScopeStackOp::Push(scope) => stack.push(*scope),
+                ScopeStackOp::Pop(n) => {
+                    let new_len = stack.len().saturating_sub(*n);
+                    stack.truncate(new_len);
+                }
+                _ => {}
+            }
+        }
+        if pos < line.len() {
+            record_definition(&stack, &line[pos..], &mut found);
+        }
+    }
+
+    found
+}
+
+fn record_definition(stack: &[Scope], text: &str, out: &mut Vec<(String, &'static str)>) {
+    let name = text.trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return;
+    }
+    for scope in stack.iter().rev() {
+        let s = scope.to_string();
+        if s.contains("entity.name.function") {
+            out.push((name.to_string(), "fn"));
+            return;
+        }
+        if s.contains("entity.name.type") || s.contains("entity.name.struct") {
+            out.push((name.to_string(), "type"));
+            return;
+        }
+    }
+}
+
+// the class attribute `ClassedHTMLGenerator` (with `ClassStyle::Spaced`) wrote on a token's
+// own `<span>`, one space-separated word per scope component it carries, e.g. a function
+// definition's name comes out `class="entity name function rust"`. `None` for anything that
+// isn't a span's opening tag (closing tags, or any other markup).
+fn span_class(tag: &str) -> Option<&str> {
+    if !tag.starts_with("<span ") {
+        return None;
+    }
+    let start = tag.find("class=\"")? + "class=\"".len();
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+// A token only stands for a *reference* to a definition - as opposed to the definition's own
+// name, a field/variable that merely shares its text, or a module path segment like the
+// `fmt` in `fmt::Display` - when syntect itself tagged it as a name: `entity`/`support`
+// classes cover a definition or a recognized builtin, and `type`/`function` cover the
+// generic-parameter and call-site uses `rank`/`select`/`SkipList` actually need linked.
+// `member` (struct fields) and `path` (`use` and `::`-qualified path segments) are excluded
+// outright, since their text coincidentally matching a symbol name doesn't make them one.
+fn token_refs_definition(class_attr: &str) -> bool {
+    let mut is_reference = false;
+    let mut is_excluded = false;
+    for class in class_attr.split_whitespace() {
+        match class {
+            "entity" | "support" | "type" | "function" => is_reference = true,
+            "member" | "path" | "keyword" | "comment" | "string" => is_excluded = true,
+            _ => {}
+        }
+    }
+    is_reference && !is_excluded
+}
+
+// Wraps a token's generated `<span>...</span>` in an `<a href="...">` pointing at its
+// definition, the same jump-to-definition rustdoc gives you from recorded source spans,
+// whenever its text matches a name in `symbol_map` *and* its own scope (read back off the
+// class `ClassedHTMLGenerator` already wrote on its span) says it's actually a reference to
+// one, rather than just a field, variable, or path segment that happens to share the text -
+// and its definition isn't in the very section being rendered (`current_out` and
+// `current_anchor`), so neither the defining occurrence nor any other use in that same
+// section links back to the section it's already sitting in.
+fn link_definitions(
+    html_fragment: &str,
+    symbol_map: &SymbolMap,
+    current_out: &str,
+    current_anchor: &str,
+) -> String {
+    let mut out = String::with_capacity(html_fragment.len());
+    let mut rest = html_fragment;
+    loop {
+        let lt = match rest.find('<') {
+            Some(lt) => lt,
+            None => {
+                out.push_str(rest);
+                break;
+            }
+        };
+        out.push_str(&rest[..lt]);
+        rest = &rest[lt..];
+
+        let gt = match rest.find('>') {
+            Some(gt) => gt,
+            None => {
+                out.push_str(rest);
+                break;
+            }
+        };
+        let tag = &rest[..=gt];
+        let class_attr = span_class(tag);
+        out.push_str(tag);
+        rest = &rest[gt + 1..];
+
+        let next_lt = match rest.find('<') {
+            Some(next_lt) => next_lt,
+            None => {
+                out.push_str(rest);
+                break;
+            }
+        };
+        let token = &rest[..next_lt];
+        rest = &rest[next_lt..];
+
+        let target = class_attr
+            .filter(|c| token_refs_definition(c))
+            .and_then(|_| symbol_map.get(token.trim()))
+            .filter(|(file, anchor)| file != current_out || anchor != current_anchor);
+        match target {
+            Some((file, anchor)) => {
+                let href = if file == current_out {
+                    format!("#{}", anchor)
+                } else {
+                    format!("{}#{}", file, anchor)
+                };
+                out.push_str(&format!("<a href=\"{}\">{}</a>", href, token));
+            }
+            None => out.push_str(token),
+        }
+    }
+    out
+}
+
+// Render-time state that's fixed for the whole page a row belongs to - the syntax set, the
+// site-wide symbol map (once the first pass has built it), and the file being written - so
+// `flush_row` can thread them along as one argument instead of a pile of unrelated ones.
+struct RenderCtx<'a> {
+    ss: &'a SyntaxSet,
+    symbol_map: Option<&'a SymbolMap>,
+    current_out: &'a str,
+}
+
+fn output_code_block(
+    ctx: &RenderCtx,
+    mut html: impl Write,
+    src_text: &str,
+    current_anchor: &str,
+) -> Result<(), std::io::Error> {
+    let sr_rs = ctx.ss.find_syntax_by_extension("rs").unwrap();
     let mut rs_html_generator =
-        ClassedHTMLGenerator::new_with_class_style(sr_rs, &ss, ClassStyle::Spaced);
+        ClassedHTMLGenerator::new_with_class_style(sr_rs, ctx.ss, ClassStyle::Spaced);
     for line in LinesWithEndings::from(src_text) {
         rs_html_generator
             .parse_html_for_line_which_includes_newline(line)
             .unwrap();
     }
     let html_rs = rs_html_generator.finalize();
+    let html_rs = match ctx.symbol_map {
+        Some(map) => link_definitions(&html_rs, map, ctx.current_out, current_anchor),
+        None => html_rs,
+    };
 
     writeln!(html, "<div class=\"code_block\"><pre class=\"code\">")?;
     writeln!(html, "{}", html_rs)?;
@@ -44,15 +273,80 @@ fn output_doc_block(mut html: impl Write, doc_text: &str) -> Result<(), std::io:
     Ok(())
 }
 
+fn output_nav(
+    mut html: impl Write,
+    pages: &[Page],
+    current_out: &str,
+) -> Result<(), std::io::Error> {
+    writeln!(html, "  <div class=\"site_nav\">")?;
+    if current_out != "index.html" {
+        writeln!(html, "    <a href=\"index.html\">index</a>")?;
+    } else {
+        writeln!(html, "    <span class=\"current\">index</span>")?;
+    }
+    for page in pages {
+        if page.out == current_out {
+            writeln!(html, "    <span class=\"current\">{}</span>", page.title)?;
+        } else {
+            writeln!(html, "    <a href=\"{}\">{}</a>", page.out, page.title)?;
+        }
+    }
+    writeln!(html, "  </div>")?;
+    Ok(())
+}
+
+// A row pairs whatever `//-` narrative has built up since the last one with whatever code
+// followed it, mirroring the two-column, doc-next-to-the-code-it-explains layout the rest
+// of `make_html.css` expects. Rows with neither are skipped, so a page that opens straight
+// into a heading (like `skip_list.rs`) doesn't get an empty leading row. Code that appears before the
+// page's first heading - or on a page with no heading at all, like `main.rs` - falls back to
+// the reserved `top` anchor, so it still gets indexed instead of silently dropped.
+fn flush_row(
+    mut html: impl Write,
+    ctx: &RenderCtx,
+    doc_buf: &str,
+    code_buf: &str,
+    anchor: Option<&str>,
+    symbols: &mut Vec<(String, &'static str, String)>,
+) -> Result<(), std::io::Error> {
+    let doc_trim = doc_buf.trim();
+    let code_trim = code_buf.trim_end();
+    if doc_trim.is_empty() && code_trim.is_empty() {
+        return Ok(());
+    }
+    let anchor = anchor.unwrap_or("top");
+
+    writeln!(html, "  <div class=\"row\">")?;
+    writeln!(html, "  <div class=\"doc_group\" id=\"{}\">", anchor)?;
+    if !doc_trim.is_empty() {
+        output_doc_block(&mut html, doc_buf)?;
+    }
+    writeln!(html, "  </div>")?;
+    if !code_trim.is_empty() {
+        output_code_block(ctx, &mut html, code_trim, anchor)?;
+        for (name, kind) in scan_definitions(ctx.ss, code_trim) {
+            symbols.push((name, kind, anchor.to_string()));
+        }
+    }
+    writeln!(html, "  </div>")?;
+
+    Ok(())
+}
+
+// Rendering a page and indexing it are the same walk over its `//-` sections and code, so
+// `main` runs this twice: once per page with `html` set to `io::sink()` and `symbol_map`
+// `None`, purely to harvest the `PageIndex` of every definition site before any of them are
+// known to link to; and once for real, now that a complete `SymbolMap` exists, so code on
+// any page can be cross-linked to a definition wherever it lives.
 fn output_html(
     ss: &SyntaxSet,
     src_file: &str,
+    mut html: impl Write,
     out_file: &str,
     title: &str,
-) -> Result<(), std::io::Error> {
-    let html_file = File::create(Path::new(out_file))?;
-    let mut html = BufWriter::new(&html_file);
-
+    pages: &[Page],
+    symbol_map: Option<&SymbolMap>,
+) -> Result<PageIndex, std::io::Error> {
     // write html header
     writeln!(html, "<!DOCTYPE html>")?;
     writeln!(html, "<html>")?;
@@ -66,61 +360,214 @@ fn output_html(
     writeln!(html, "  </head>")?;
     writeln!(html, "  <body>")?;
 
-    // Load code, split into documentation blocks and code blocks
-    let mut reading_doc = false;
-    let mut strbuf = "".to_string();
-    writeln!(html, "  <div class=\"row\">")?;
-    writeln!(html, "  <div class=\"doc_group\">")?;
-    for line in read_to_string(src_file).unwrap().lines() {
-        if line.starts_with("///") {
-            if !reading_doc {
-                reading_doc = true;
-                let code_buf = strbuf.trim_end();
-                if code_buf.len() > 0 {
-                    writeln!(html, "  </div>")?;
-                    output_code_block(&ss, &mut html, &code_buf)?;
-                    writeln!(html, "  </div>")?;
-                    writeln!(html, "  <div class=\"row\">")?;
-                    writeln!(html, "  <div class=\"doc_group\">")?;
-                }
-                strbuf = "".to_string();
+    output_nav(&mut html, pages, out_file)?;
+
+    // Load code, pairing up each run of `//-` narrative with the code that follows it. A
+    // narrative line starts a new section (and gets its own anchor) when it's immediately
+    // followed by another narrative line made up only of `=` or `-` characters, the same
+    // setext heading underline CommonMark itself recognizes.
+    let content = read_to_string(src_file).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+
+    let ctx = RenderCtx {
+        ss,
+        symbol_map,
+        current_out: out_file,
+    };
+
+    let mut seen_anchors = HashSet::new();
+    // reserve "top", the fallback anchor `flush_row` uses for code that precedes the page's
+    // first heading, so a real heading that happens to slugify to "top" gets bumped instead
+    // of silently colliding with it
+    seen_anchors.insert("top".to_string());
+    let mut sections = Vec::new();
+    let mut symbols = Vec::new();
+
+    let mut doc_buf = String::new();
+    let mut code_buf = String::new();
+    let mut current_anchor: Option<String> = None;
+    let mut last_was_doc = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        // doc comment warning suppression
+        if line.starts_with("#[allow(unused_doc_comments)]") {
+            continue;
+        }
+        if line.starts_with("///") || line.starts_with("//-") {
+            let text = line[3..].trim();
+            let starts_heading = !text.is_empty()
+                && lines.get(i + 1).is_some_and(|next| {
+                    if next.starts_with("///") || next.starts_with("//-") {
+                        let next_text = next[3..].trim();
+                        !next_text.is_empty()
+                            && (next_text.chars().all(|c| c == '=')
+                                || next_text.chars().all(|c| c == '-'))
+                    } else {
+                        false
+                    }
+                });
+            if starts_heading {
+                flush_row(
+                    &mut html,
+                    &ctx,
+                    &doc_buf,
+                    &code_buf,
+                    current_anchor.as_deref(),
+                    &mut symbols,
+                )?;
+                doc_buf.clear();
+                code_buf.clear();
+                let anchor = unique_anchor(&slugify(text), &mut seen_anchors);
+                sections.push((text.to_string(), anchor.clone()));
+                current_anchor = Some(anchor);
             }
-            strbuf += &line[3..];
-            strbuf += "\n";
+            doc_buf.push_str(text);
+            doc_buf.push('\n');
+            last_was_doc = true;
+        } else if line.trim().is_empty() && last_was_doc {
+            // a blank line between two `//-` paragraphs is a paragraph break, not code
+            doc_buf.push('\n');
         } else {
-            if reading_doc {
-                reading_doc = false;
-                output_doc_block(&mut html, &strbuf)?;
-                strbuf = "".to_string();
-            }
-            // section dividers
-            if line.starts_with("//-") {
-                writeln!(html, "  </div>")?;
-                writeln!(html, "  </div>")?;
-                writeln!(html, "  <div class=\"row\">")?;
-                writeln!(html, "  <div class=\"doc_group\">")?;
-            // doc comment warning suppression
-            } else if !line.starts_with("#[allow(unused_doc_comments)]") {
-                strbuf += &(line.to_owned() + "\n");
-            }
+            code_buf.push_str(line);
+            code_buf.push('\n');
+            last_was_doc = false;
         }
     }
-    // output last buffer
-    if reading_doc {
-        output_doc_block(&mut html, &strbuf)?;
-    } else {
-        let code_buf = strbuf.trim_end();
-        if code_buf.len() > 0 {
-            writeln!(html, "  </div>")?;
-            output_code_block(&ss, &mut html, &code_buf)?;
+    // output last buffered row
+    flush_row(
+        &mut html,
+        &ctx,
+        &doc_buf,
+        &code_buf,
+        current_anchor.as_deref(),
+        &mut symbols,
+    )?;
+
+    // write html end
+    writeln!(html, "  </body>")?;
+    writeln!(html, "</html>")?;
+
+    Ok(PageIndex { sections, symbols })
+}
+
+// entry in the site-wide search index, one per `//-` heading and per defined item found
+struct SearchEntry {
+    label: String,
+    kind: &'static str,
+    file: String,
+    anchor: String,
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
         }
     }
+    out
+}
+
+fn output_search_index(out_file: &str, entries: &[SearchEntry]) -> Result<(), std::io::Error> {
+    let file = File::create(Path::new(out_file))?;
+    let mut w = BufWriter::new(&file);
+    writeln!(w, "[")?;
+    for (i, e) in entries.iter().enumerate() {
+        let comma = if i + 1 < entries.len() { "," } else { "" };
+        writeln!(
+            w,
+            "  {{\"label\": \"{}\", \"kind\": \"{}\", \"file\": \"{}\", \"anchor\": \"{}\"}}{}",
+            json_escape(&e.label),
+            e.kind,
+            json_escape(&e.file),
+            json_escape(&e.anchor),
+            comma
+        )?;
+    }
+    writeln!(w, "]")?;
+    Ok(())
+}
+
+// small, bundled (no build step) search box: filters `search_index.json` as you type
+const SEARCH_SCRIPT: &str = r##"  <script>
+    (function () {
+      var input = document.getElementById("search_input");
+      var results = document.getElementById("search_results");
+      if (!input || !results) { return; }
+      var index = [];
+      fetch("search_index.json").then(function (r) { return r.json(); }).then(function (data) {
+        index = data;
+      });
+      input.addEventListener("input", function () {
+        var query = input.value.trim().toLowerCase();
+        results.innerHTML = "";
+        if (query.length === 0) { return; }
+        index
+          .filter(function (e) { return e.label.toLowerCase().indexOf(query) !== -1; })
+          .slice(0, 30)
+          .forEach(function (e) {
+            var li = document.createElement("li");
+            var a = document.createElement("a");
+            a.href = e.file + "#" + e.anchor;
+            a.textContent = e.label;
+            li.appendChild(a);
+            var kind = document.createElement("span");
+            kind.className = "search_kind";
+            kind.textContent = " " + e.kind;
+            li.appendChild(kind);
+            results.appendChild(li);
+          });
+      });
+    })();
+  </script>
+"##;
+
+fn output_index(pages: &[Page]) -> Result<(), std::io::Error> {
+    let html_file = File::create(Path::new("index.html"))?;
+    let mut html = BufWriter::new(&html_file);
+
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, "<html>")?;
+    writeln!(html, "  <head>")?;
+    writeln!(html, "    <title>litcode</title>")?;
+    writeln!(html, "    <style type=\"text/css\">")?;
+    writeln!(html, "{}", include_str!("make_html.css"))?;
+    writeln!(html, "    </style>")?;
+    writeln!(html, "  </head>")?;
+    writeln!(html, "  <body>")?;
+
+    output_nav(&mut html, pages, "index.html")?;
+
+    writeln!(html, "  <div class=\"search_box\">")?;
+    writeln!(
+        html,
+        "    <input type=\"text\" id=\"search_input\" placeholder=\"Search sections and symbols...\" autocomplete=\"off\">"
+    )?;
+    writeln!(html, "    <ul id=\"search_results\"></ul>")?;
     writeln!(html, "  </div>")?;
 
-    // write html end
+    writeln!(html, "  <div class=\"doc_group\">")?;
+    writeln!(html, "  <ul class=\"page_list\">")?;
+    for page in pages {
+        writeln!(
+            html,
+            "    <li><a href=\"{}\">{}</a></li>",
+            page.out, page.title
+        )?;
+    }
+    writeln!(html, "  </ul>")?;
+    writeln!(html, "  </div>")?;
+
+    write!(html, "{}", SEARCH_SCRIPT)?;
+
     writeln!(html, "  </body>")?;
     writeln!(html, "</html>")?;
-
     Ok(())
 }
 
@@ -129,7 +576,78 @@ fn main() -> Result<(), std::io::Error> {
     // generate html
     let ss = SyntaxSet::load_defaults_newlines();
 
-    output_html(&ss, "src/skip_list.rs", "skip_list.html", "Skip List in Rust")?;
+    let pages = vec![
+        Page {
+            src: "src/skip_list.rs".to_string(),
+            out: "skip_list.html".to_string(),
+            title: "Skip List in Rust".to_string(),
+        },
+        Page {
+            src: "src/main.rs".to_string(),
+            out: "main.html".to_string(),
+            title: "main".to_string(),
+        },
+    ];
+
+    // first pass: scan every page, writing its HTML to a sink, purely to learn where every
+    // `//-` heading and defined item ends up, before any page is rendered for real
+    let mut symbol_map: SymbolMap = HashMap::new();
+    let mut search_entries = Vec::new();
+    let mut seen_symbols = HashSet::new();
+    for page in &pages {
+        let page_index = output_html(
+            &ss,
+            &page.src,
+            std::io::sink(),
+            &page.out,
+            &page.title,
+            &pages,
+            None,
+        )?;
+        for (heading, anchor) in page_index.sections {
+            search_entries.push(SearchEntry {
+                label: heading,
+                kind: "section",
+                file: page.out.clone(),
+                anchor,
+            });
+        }
+        // keep only the first definition site of a given name and kind, so e.g. `SkipList` being
+        // named again at every later `impl` block doesn't crowd the index with duplicates,
+        // and so later uses of that name link back to its original definition
+        for (name, kind, anchor) in page_index.symbols {
+            if seen_symbols.insert((name.clone(), kind)) {
+                symbol_map
+                    .entry(name.clone())
+                    .or_insert_with(|| (page.out.clone(), anchor.clone()));
+                search_entries.push(SearchEntry {
+                    label: name,
+                    kind,
+                    file: page.out.clone(),
+                    anchor,
+                });
+            }
+        }
+    }
+
+    // second pass: render every page for real, now that `symbol_map` knows where every
+    // definition lives, so `output_code_block` can cross-link identifiers to them
+    for page in &pages {
+        let html_file = File::create(Path::new(&page.out))?;
+        let html = BufWriter::new(&html_file);
+        output_html(
+            &ss,
+            &page.src,
+            html,
+            &page.out,
+            &page.title,
+            &pages,
+            Some(&symbol_map),
+        )?;
+    }
+
+    output_index(&pages)?;
+    output_search_index("search_index.json", &search_entries)?;
 
     // ---------------------------------------------------------------------------------------------
     // generate css files for themes
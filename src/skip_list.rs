@@ -28,11 +28,13 @@ use std::cmp::PartialOrd;
 use std::fmt;
 use rand::Rng;
 
-//- The number of "fast-lane" layers affects the performance traits of the list. A single layer is
-//- equivalent to a flat linked-list, with no performance gains. But too many layers increases the
-//- memory usage with duplicated data. The ideal number of layers is the `log2(N)` of the number of elements
-//- in the list, but here I chose a smaller number to make debugging simpler.
-const SKIP_LIST_LAYERS: usize = 3;
+//- The number of "fast-lane" layers a skip list needs to stay `O(log(N))` grows with the number
+//- of elements in it, so a fixed layer count either wastes memory on small lists or, worse, caps
+//- out and degrades towards a plain linked list once `N` grows past it. Instead, `layers` and
+//- `head` below grow on demand as taller towers are rolled, up to `MAX_LEVEL`, which bounds how
+//- sparse the top layer can possibly get. 16 levels comfortably covers lists up into the millions
+//- of elements, since each level only has roughly half the nodes of the one below it.
+const MAX_LEVEL: usize = 16;
 
 //- The `SkipList` struct is the public-facing type which users can interact with. It defines a number
 //- of public methods below to allow inserting and retrieving values, but the internal data is private.
@@ -49,20 +51,56 @@ const SKIP_LIST_LAYERS: usize = 3;
 //- The tradeoff is an additional layer of indirection, and the requirement that these indexes cannot
 //- change or move around. But it is not 'unsafe', as to mutate any of the internal data, a proper mutable
 //- reference to the `SkipList` is still required.
+//- Deleting a value is complicated by the fact that a node's `usize` index is held by the `next`
+//- and `down` links of other nodes, so it cannot change or move while the node is linked in.
+//- So `remove` cannot `swap_remove` a vacated
+//- slot out of a layer's `Vec`; instead each layer keeps a `free` list of indices whose node is
+//- logically dead, and `insert` prefers popping a free slot over growing the `Vec`.
+
+//- Each forward link also carries a `span`: the number of bottom-layer nodes it jumps over, the
+//- same trick Redis's sorted sets use to turn a skip list into an indexed, ranked collection.
+//- A bottom-layer link always has span 1, since it steps to the very next element; a fast-lane
+//- link further up has a larger span, since it leapfrogs however many bottom-layer nodes sort
+//- between it and whatever it points to. Since the virtual "start" of a layer, above its `head`,
+//- isn't itself a node, `head_span` plays the same role for that implicit first hop as `span`
+//- does for every other link. Summing the spans walked while descending towards a value gives
+//- its 0-based rank; walking forward while the running sum stays within bounds gives the `n`th
+//- smallest value directly, without ever touching the bottom layer unless the answer is shallow.
 pub struct SkipList<T> {
-    layers: [Vec<SkipListNode<T>>; SKIP_LIST_LAYERS],
-    head: [Option<usize>; SKIP_LIST_LAYERS],
+    layers: Vec<Vec<SkipListNode<T>>>,
+    head: Vec<Option<usize>>,
+    head_span: Vec<usize>,
+    free: Vec<Vec<usize>>,
 }
 
 //- The SkipListNode is an internal type storing links between values, and links from fast-lane values
 //- down to lower layers. Only the lowest layer in the SkipList, `layers[0]`, stores all values. Nodes in
-//- the lowest layer have no `down` references.
+//- the lowest layer have no `down` references. `span` is only meaningful while `next` is `Some`; a node
+//- with no `next` has nothing to jump over, so its `span` is left at `0` and never read.
 struct SkipListNode<T> {
     value: T,
     next: Option<usize>,
+    span: usize,
     down: Option<usize>,
 }
 
+//- `SkipListIter` is the iterator returned by `iter` and `range`: it walks `layers[0]` forward via
+//- `next` the same way `Display` does, just yielding references instead of building a string.
+struct SkipListIter<'a, T> {
+    layer: &'a Vec<SkipListNode<T>>,
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for SkipListIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+        let node = &self.layer[idx];
+        self.next = node.next;
+        Some(&node.value)
+    }
+}
+
 //- Implementation
 //- ==
 //=
@@ -70,13 +108,15 @@ struct SkipListNode<T> {
 
 //- Implementing `Default` as the constructor allows this struct to be used automatically in any other types
 //- which implement the `Default` trait, including types defined with the `#[derive(Default)]` attribute.
-//- The initial data is a list of empty `Vec`s for each layer of the data structure, and a set of empty head
-//- references which will eventually point to the first node in each layer.
+//- The initial data is a single, empty layer (every list needs at least a bottom layer), with `head`,
+//- `head_span`, and `free` kept in lockstep with however many layers `layers` grows to hold.
 impl<T> Default for SkipList<T> {
     fn default() -> Self {
         Self {
-            layers: [const { Vec::new() }; SKIP_LIST_LAYERS],
-            head: [const { None }; SKIP_LIST_LAYERS],
+            layers: vec![Vec::new()],
+            head: vec![None],
+            head_span: vec![0],
+            free: vec![Vec::new()],
         }
     }
 }
@@ -94,90 +134,143 @@ impl<T: PartialOrd + Clone> SkipList<T> {
 //- to speed up searching for the value. The more fast-lanes a value is in, the faster it can be found, but if too
 //- many values are inserted into the fast lanes, the whole list slows down, up to a worst-case amoratized scenario
 //- of linear time.
+
+//- The tower height for the new node is rolled with repeated coin flips: starting at height 1, each flip has a
+//- 50% chance of growing the tower one level further, capped at `MAX_LEVEL`. This gives the classic geometric
+//- distribution where every node has height 1, about half reach height 2, a quarter reach height 3,
+//- and so on, so the expected number of populated layers works out to `log2(N)` on its own, without ever having
+//- to know `N` up front.
     pub fn insert(&mut self, v: T) {
-        // randomize number of insert layers
-        let num_insert_layers = if self.head[0].is_none() {
-            // insert node into all layers
-            SKIP_LIST_LAYERS
-        } else {
-            1 + rand::rng().random_range(0..SKIP_LIST_LAYERS)
-        };
+        // roll the new node's tower height
+        let mut height = 1;
+        let mut rng = rand::rng();
+        while height < MAX_LEVEL && rng.random::<bool>() {
+            height += 1;
+        }
+
+        let num_layers = self.layers.len();
 
 //- Finding the insertion point requires going through the layers, first from the most-sparse layer, then down to
 //- the most complete layer, until the insert location of the value is found in all layers. A list of the nodes
 //- closest to the insert point is kept to quickly update these nodes with pointers to the newly inserted node.
-        let mut layer_start_idx = self.head[SKIP_LIST_LAYERS-1];
-        // store list of node idxs for insertion
-        let mut insert_list = [const { None }; SKIP_LIST_LAYERS];
+//- Alongside it, `insert_rank` accumulates the span of every link walked across, so that once the search
+//- reaches layer 0 it holds the new value's 0-based rank, and `pred_rank` remembers that running total as
+//- it stood at each layer, which is exactly what's needed below to split a predecessor's span in two.
+        let mut node_idx: Option<usize> = None;
+        let mut insert_rank: usize = 0;
+        // store list of node idxs for insertion, one per currently populated layer
+        let mut insert_list: Vec<Option<usize>> = vec![None; num_layers];
+        // running span total at the point the search settled in each layer
+        let mut pred_rank: Vec<usize> = vec![0; num_layers];
         // for each layer
-        for rlayer in 0..SKIP_LIST_LAYERS {
-            let layer = SKIP_LIST_LAYERS - 1 - rlayer;
-            let mut node_idx = layer_start_idx.clone();
-            // if front insertion
-            if node_idx.is_none() || v < self.layers[layer][node_idx.unwrap()].value  {
-                insert_list[layer] = None;
-                // set next layer start idx to none
-                if layer > 0 {
-                    layer_start_idx = self.head[layer-1];
-                }
-
-            // if existing element
-            } else if v == self.layers[layer][node_idx.unwrap()].value {
-                return;
-
-            // else, find insertion index of current layer
-            } else {
-                loop {
-                    // check the next node in the sequence
-                    let next_idx = self.layers[layer][node_idx.unwrap()].next;
-                    // if the end of the sequence has been reached, use last node
-                    if next_idx.is_none() {
-                        break;
-                    }
-                    // if the next node is greater than the value, use last node
-                    if v < self.layers[layer][next_idx.unwrap()].value {
-                        break;
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
+            loop {
+                // check the next node in the sequence, walking forward (and through the
+                // virtual start of the layer, via head/head_span) while it still sorts
+                // before v, accumulating the span of each link taken
+                let (next_idx, span) = match node_idx {
+                    Some(idx) => (self.layers[layer][idx].next, self.layers[layer][idx].span),
+                    None => (self.head[layer], self.head_span[layer]),
+                };
+                match next_idx {
+                    Some(idx) if self.layers[layer][idx].value < v => {
+                        node_idx = next_idx;
+                        insert_rank += span;
                     }
-                    // continue
-                    node_idx = next_idx;
+                    // a duplicate value already exists; nothing to insert
+                    Some(idx) if self.layers[layer][idx].value == v => return,
+                    _ => break,
                 }
-
-                // set insert index to found node
-                insert_list[layer] = node_idx.clone();
-                // set next layer start idx to down value of found node
-                layer_start_idx = self.layers[layer][node_idx.unwrap()].down;
             }
+
+            insert_list[layer] = node_idx;
+            pred_rank[layer] = insert_rank;
+            // set next layer start idx to down value of found node (or stay at the
+            // virtual start, if no predecessor was walked past in this layer)
+            node_idx = match node_idx {
+                Some(idx) => self.layers[layer][idx].down,
+                None => None,
+            };
+        }
+
+//- If the rolled height reaches higher than any tower inserted so far, new, empty layers are appended on top,
+//- with their `head` pointing nowhere yet. A brand new layer has no nodes to search through, so its insertion
+//- point is trivially its own (currently empty, zero-`head_span`) head, which the splicing loop below handles
+//- the same way as any other layer.
+        while self.layers.len() < height {
+            self.layers.push(Vec::new());
+            self.head.push(None);
+            self.head_span.push(0);
+            self.free.push(Vec::new());
+            insert_list.push(None);
+            pred_rank.push(0);
         }
 
 //- Now that I have a list of nodes which need to be updated, the new node can be inserted. Starting with
 //- the lowest layer this time, the node is inserted into the unsorted `Vec` of all nodes, and the index to
 //- the new node is set as the `next` of the node it was inserted after.
 
-//- I continue up the layers for the amount of layers given by the random number generator. For each layer,
-//- the new node is inserted, the neighboring links are updated, and the `down` is set to the index of the
-//- node in the lower layer.
+//- I continue up the layers for the rolled tower height. For each layer, the new node is inserted, the
+//- neighboring links are updated, and the `down` is set to the index of the node in the lower layer.
+//- Splicing in the new node splits whatever span used to jump straight from the predecessor to its old
+//- `next` into two: `pred_span` is the distance from the predecessor up to the new node, and the new
+//- node's own outgoing span is whatever distance is left over to reach the old `next`.
         let mut last_insert: Option<usize> = None;
-        for layer in 0..num_insert_layers {
-            // get old next value of insert-parent node
-            let next = if let Some(insert_idx) = insert_list[layer] {
-                self.layers[layer][insert_idx].next.clone()
-            } else {
-                self.head[layer]
+        for layer in 0..height {
+            // get old next value and span of insert-parent node (or the layer's virtual start)
+            let (next, old_span) = match insert_list[layer] {
+                Some(insert_idx) => (self.layers[layer][insert_idx].next, self.layers[layer][insert_idx].span),
+                None => (self.head[layer], self.head_span[layer]),
             };
-            // insert new node into memory
-            self.layers[layer].push(
-                SkipListNode {
-                    value: v.clone(),
-                    next,
-                    down: last_insert.clone(),
-                }
-            );
-            last_insert = Some(self.layers[layer].len() - 1);
-            // update next value of insert-parent node
-            if let Some(insert_idx) = insert_list[layer] {
-                self.layers[layer][insert_idx].next = last_insert.clone();
+            let pred_span = insert_rank - pred_rank[layer] + 1;
+            let new_span = if next.is_some() { old_span + pred_rank[layer] - insert_rank } else { 0 };
+
+            // insert new node into memory, reusing a freed slot from a prior `remove` if one
+            // is available rather than growing the layer's `Vec`
+            let new_node = SkipListNode {
+                value: v.clone(),
+                next,
+                span: new_span,
+                down: last_insert,
+            };
+            last_insert = Some(if let Some(free_idx) = self.free[layer].pop() {
+                self.layers[layer][free_idx] = new_node;
+                free_idx
             } else {
-                self.head[layer] = last_insert.clone();
+                self.layers[layer].push(new_node);
+                self.layers[layer].len() - 1
+            });
+            // update next value and span of insert-parent node
+            match insert_list[layer] {
+                Some(insert_idx) => {
+                    self.layers[layer][insert_idx].next = last_insert;
+                    self.layers[layer][insert_idx].span = pred_span;
+                }
+                None => {
+                    self.head[layer] = last_insert;
+                    self.head_span[layer] = pred_span;
+                }
+            }
+        }
+
+//- Every layer taller than the new node's own tower still has a link passing somewhere over the new
+//- node's position without stopping at it, since the new bottom-layer element now sits underneath it;
+//- that link's span grows by one to account for it. A link that doesn't actually reach past the
+//- insertion point (the layer's predecessor is its own tail, or the layer is entirely empty) has
+//- nothing to adjust.
+        for layer in height..num_layers {
+            match insert_list[layer] {
+                Some(insert_idx) => {
+                    if self.layers[layer][insert_idx].next.is_some() {
+                        self.layers[layer][insert_idx].span += 1;
+                    }
+                }
+                None => {
+                    if self.head[layer].is_some() {
+                        self.head_span[layer] += 1;
+                    }
+                }
             }
         }
     }
@@ -187,39 +280,255 @@ impl<T: PartialOrd + Clone> SkipList<T> {
 //- the next search can begin. This continues until the value is found, at which point we know the list contains
 //- the value and `true` is returned, or until the node is confirmed missing and `false` can be returned.
     pub fn contains(&self, v: T) -> bool {
+        let num_layers = self.layers.len();
         // starting point of layer
-        let mut layer_start_idx = self.head[SKIP_LIST_LAYERS-1];
+        let mut layer_start_idx = self.head[num_layers-1];
         // for each layer
-        for rlayer in 0..SKIP_LIST_LAYERS {
-            let layer = SKIP_LIST_LAYERS - 1 - rlayer;
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
             let mut node_idx = layer_start_idx.clone();
-            // if empty
-            if node_idx.is_none() {
-                return false;
+            let mut pred_idx: Option<usize> = None;
+            // advance past nodes that sort strictly before v
+            while node_idx.is_some() && self.layers[layer][node_idx.unwrap()].value < v {
+                pred_idx = node_idx;
+                node_idx = self.layers[layer][node_idx.unwrap()].next;
             }
-            // if immediate match
-            if v == self.layers[layer][node_idx.unwrap()].value {
+            // match found in this layer
+            if node_idx.is_some() && self.layers[layer][node_idx.unwrap()].value == v {
                 return true;
             }
-            // keep looking
+            // descend to the next layer via the local predecessor's down pointer (or the
+            // next layer's head, if no predecessor was walked past in this layer)
+            layer_start_idx = match pred_idx {
+                Some(idx) => self.layers[layer][idx].down,
+                None if layer > 0 => self.head[layer-1],
+                None => None,
+            };
+        }
+        // no match
+        false
+    }
+
+//- `rank` counts how many elements sort strictly before `v`, which is to say the 0-based index `v`
+//- would occupy if it were inserted. It's the same top-down span-summing walk `insert` uses to find
+//- `insert_rank`, just read-only: every link taken, whether from a node or from a layer's virtual
+//- start via `head_span`, adds its span to the running total.
+    pub fn rank(&self, v: &T) -> usize {
+        let num_layers = self.layers.len();
+        let mut node_idx: Option<usize> = None;
+        let mut rank_acc: usize = 0;
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
             loop {
-                let next_idx = self.layers[layer][node_idx.unwrap()].next;
-                // end of list
-                if next_idx.is_none() {
-                    break;
+                let (next_idx, span) = match node_idx {
+                    Some(idx) => (self.layers[layer][idx].next, self.layers[layer][idx].span),
+                    None => (self.head[layer], self.head_span[layer]),
+                };
+                match next_idx {
+                    Some(idx) if self.layers[layer][idx].value < *v => {
+                        node_idx = next_idx;
+                        rank_acc += span;
+                    }
+                    _ => break,
                 }
-                // passed value
-                if v < self.layers[layer][next_idx.unwrap()].value {
+            }
+            // descend via down pointer (or stay at the virtual start)
+            if layer > 0 {
+                node_idx = match node_idx {
+                    Some(idx) => self.layers[layer][idx].down,
+                    None => None,
+                };
+            }
+        }
+        rank_acc
+    }
+
+//- `select` is `rank` run in reverse: instead of summing spans to reach a value, it walks forward
+//- for as long as the running total stays within `n + 1`, taking the biggest jump available at each
+//- layer before dropping down to the next one. Once the bottom layer is exhausted, the running
+//- total lands exactly on `n + 1` if, and only if, the list actually has an `n`th element.
+    pub fn select(&self, n: usize) -> Option<&T> {
+        let num_layers = self.layers.len();
+        let mut node_idx: Option<usize> = None;
+        let mut traversed: usize = 0;
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
+            loop {
+                let (next_idx, span) = match node_idx {
+                    Some(idx) => (self.layers[layer][idx].next, self.layers[layer][idx].span),
+                    None => (self.head[layer], self.head_span[layer]),
+                };
+                if next_idx.is_none() || traversed + span > n + 1 {
                     break;
                 }
-                // continue
                 node_idx = next_idx;
+                traversed += span;
+            }
+            // descend via down pointer (or stay at the virtual start)
+            if layer > 0 {
+                node_idx = match node_idx {
+                    Some(idx) => self.layers[layer][idx].down,
+                    None => None,
+                };
             }
-            // set next layer start idx to down value of last closest node
-            layer_start_idx = self.layers[layer][node_idx.unwrap()].down;
         }
-        // no match
-        false
+        if traversed == n + 1 {
+            node_idx.map(|idx| &self.layers[0][idx].value)
+        } else {
+            None
+        }
+    }
+
+//- Finds the first bottom-layer node holding a value not less than `v`, using the same
+//- top-down descent `rank`/`select` use: walk forward through a layer while the next value still
+//- sorts before `v`, then drop down a layer, carrying the predecessor found so far along. Once
+//- layer 0 is reached and walked, `node_idx` is the last value strictly less than `v`, so the
+//- answer is one step past it (or the bottom layer's head, if nothing sorts before `v` at all).
+    fn find_ge(&self, v: &T) -> Option<usize> {
+        let num_layers = self.layers.len();
+        let mut node_idx: Option<usize> = None;
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
+            loop {
+                let next_idx = match node_idx {
+                    Some(idx) => self.layers[layer][idx].next,
+                    None => self.head[layer],
+                };
+                match next_idx {
+                    Some(idx) if self.layers[layer][idx].value < *v => node_idx = next_idx,
+                    _ => break,
+                }
+            }
+            if layer > 0 {
+                node_idx = match node_idx {
+                    Some(idx) => self.layers[layer][idx].down,
+                    None => None,
+                };
+            }
+        }
+        match node_idx {
+            Some(idx) => self.layers[0][idx].next,
+            None => self.head[0],
+        }
+    }
+
+//- `iter` exposes the sorted order `Display` otherwise keeps locked up in a formatted string, by
+//- walking the bottom layer from its head forward.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        SkipListIter {
+            layer: &self.layers[0],
+            next: self.head[0],
+        }
+    }
+
+//- `range` yields every value in `[lo, hi]` in sorted order, the main reason databases like
+//- Lucene and LevelDB reach for a skip list over a hash map: rather than scanning from the head,
+//- `find_ge` reuses the logarithmic descent to jump straight to the first qualifying value, then
+//- `take_while` follows `next` links only as long as they stay within `hi`.
+    pub fn range<'a>(&'a self, lo: &T, hi: &'a T) -> impl Iterator<Item = &'a T> {
+        SkipListIter {
+            layer: &self.layers[0],
+            next: self.find_ge(lo),
+        }.take_while(move |v| *v <= hi)
+    }
+
+//- `remove` walks the layers the same way `contains` does, but instead of just checking for a match, it
+//- records two things per layer: the predecessor whose `next` will need to be spliced past the removed
+//- node (or `None` if the removed node is that layer's head), and the index of the node holding `v` in
+//- that layer, if it appears there at all. A value is only ever present in a contiguous run of layers, from
+//- the top of its own tower down through layer 0, so finding it missing at a layer just means descending through the
+//- predecessor's `down` pointer as usual.
+    pub fn remove(&mut self, v: T) -> bool {
+        let num_layers = self.layers.len();
+        // starting point of layer
+        let mut layer_start_idx = self.head[num_layers-1];
+        // predecessor to splice past the removed node in each layer, None means layer head
+        let mut remove_pred: Vec<Option<usize>> = vec![None; num_layers];
+        // index of the node holding `v` in each layer, if present there
+        let mut remove_node: Vec<Option<usize>> = vec![None; num_layers];
+        let mut found = false;
+
+        // for each layer
+        for rlayer in 0..num_layers {
+            let layer = num_layers - 1 - rlayer;
+            let mut node_idx = layer_start_idx.clone();
+            let mut pred_idx: Option<usize> = None;
+
+            // advance past nodes that sort strictly before v
+            while node_idx.is_some() && self.layers[layer][node_idx.unwrap()].value < v {
+                pred_idx = node_idx;
+                node_idx = self.layers[layer][node_idx.unwrap()].next;
+            }
+
+            // found v in this layer
+            if node_idx.is_some() && self.layers[layer][node_idx.unwrap()].value == v {
+                found = true;
+                remove_node[layer] = node_idx;
+            }
+            // record the predecessor walked past in this layer regardless of whether v was
+            // found here, since the span bookkeeping below needs it either way
+            remove_pred[layer] = pred_idx;
+
+            // descend to the next layer via the local predecessor's down pointer (or the
+            // next layer's head, if no predecessor was walked past in this layer). This has
+            // to use the predecessor rather than the matched node itself, since the matched
+            // node's down pointer skips straight to its own counterpart one layer down and
+            // would otherwise drop any lower-layer-only nodes that sort before it.
+            layer_start_idx = match pred_idx {
+                Some(idx) => self.layers[layer][idx].down,
+                None if layer > 0 => self.head[layer-1],
+                None => None,
+            };
+        }
+
+        if !found {
+            return false;
+        }
+
+        // unlink the removed node from every layer it appeared in, merging the span its two
+        // surrounding links used to cover into one, and reclaim its slot. The merged span is
+        // one less than the sum of the two it replaces, since the removed node itself no
+        // longer counts as a bottom-layer element to jump over. A predecessor left pointing
+        // at nothing (the removed node was the layer's tail) has no span left to track, so it
+        // resets to the unused convention of `0` rather than picking up a stale total that no
+        // longer points anywhere.
+        for layer in 0..num_layers {
+            if let Some(node_idx) = remove_node[layer] {
+                let next = self.layers[layer][node_idx].next;
+                let node_span = self.layers[layer][node_idx].span;
+                let merged_span = if next.is_some() {
+                    match remove_pred[layer] {
+                        Some(pred_idx) => self.layers[layer][pred_idx].span + node_span - 1,
+                        None => self.head_span[layer] + node_span - 1,
+                    }
+                } else {
+                    0
+                };
+                match remove_pred[layer] {
+                    Some(pred_idx) => {
+                        self.layers[layer][pred_idx].next = next;
+                        self.layers[layer][pred_idx].span = merged_span;
+                    }
+                    None => {
+                        self.head[layer] = next;
+                        self.head_span[layer] = merged_span;
+                    }
+                }
+                self.free[layer].push(node_idx);
+            // the removed node doesn't live in this layer, but a link here may still jump
+            // clean over its position without stopping; that link's span shrinks by one
+            // to account for it. As in `insert`, a link that doesn't actually reach past
+            // the removed node's position (the layer's predecessor is its own tail, or the
+            // layer is entirely empty) has nothing to adjust.
+            } else if let Some(pred_idx) = remove_pred[layer] {
+                if self.layers[layer][pred_idx].next.is_some() {
+                    self.layers[layer][pred_idx].span -= 1;
+                }
+            } else if self.head[layer].is_some() {
+                self.head_span[layer] -= 1;
+            }
+        }
+        true
     }
 }
 
@@ -234,6 +543,7 @@ impl<T: PartialOrd + Clone> SkipList<T> {
 //- ```
 impl<T: PartialOrd + Clone + fmt::Display> fmt::Display for SkipList<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_layers = self.layers.len();
         let mut base_list = Vec::<T>::new();
         let mut node_idx = self.head[0];
         while node_idx.is_some() {
@@ -241,8 +551,8 @@ impl<T: PartialOrd + Clone + fmt::Display> fmt::Display for SkipList<T> {
             base_list.push(node.value.clone());
             node_idx = node.next;
         }
-        let out1 = (1..SKIP_LIST_LAYERS).map(|rlayer| {
-            let layer = SKIP_LIST_LAYERS - rlayer;
+        let out1 = (1..num_layers).map(|rlayer| {
+            let layer = num_layers - rlayer;
             let mut outln = "[".to_string();
             let mut node_idx = self.head[layer];
             for b in &base_list {
@@ -293,4 +603,123 @@ mod test {
         assert_eq!(skip_list.contains(3), true);
         assert_eq!(skip_list.contains(4), false);
     }
+
+    #[test]
+    fn remove() {
+        let nums: [i64; 10] = [3, 1, 9, 12, 11, 16, 99, 18, 7, 22];
+
+        let mut skip_list = SkipList::default();
+        for n in &nums {
+            skip_list.insert(*n);
+        }
+
+        // removing a value present in the list unlinks it and reports true; checked against
+        // the `Display` output, which always walks the bottom layer in sorted order
+        assert_eq!(skip_list.remove(7), true);
+        assert!(!format!("{}", skip_list).contains(" 07, "));
+
+        // removing something already gone, or never inserted, reports false
+        assert_eq!(skip_list.remove(7), false);
+        assert_eq!(skip_list.remove(4), false);
+
+        // removing the lowest value updates each layer's head
+        assert_eq!(skip_list.remove(1), true);
+        let out = format!("{}", skip_list);
+        assert!(!out.contains(" 01, "));
+
+        // the rest of the list is unaffected
+        for n in &nums {
+            if *n != 7 && *n != 1 {
+                assert!(out.contains(&format!(" {:02}, ", n)));
+            }
+        }
+
+        // removed values can be inserted again, into freed slots where available
+        skip_list.insert(1);
+        skip_list.insert(7);
+        let out = format!("{}", skip_list);
+        assert!(out.contains(" 01, "));
+        assert!(out.contains(" 07, "));
+    }
+
+    #[test]
+    fn grows_layers_for_large_inputs() {
+        // with a fixed, tiny layer count this many elements would degrade close to a linear
+        // scan; a dynamically grown tower keeps it genuinely multi-layered instead
+        let mut skip_list = SkipList::default();
+        for n in 0..2000i64 {
+            skip_list.insert(n);
+        }
+        assert!(skip_list.layers.len() > 1);
+        for n in 0..2000i64 {
+            assert_eq!(skip_list.contains(n), true);
+        }
+        assert_eq!(skip_list.contains(2000), false);
+    }
+
+    #[test]
+    fn rank_and_select() {
+        let nums: [i64; 10] = [3, 1, 9, 12, 11, 16, 99, 18, 7, 22];
+        let mut sorted = nums.to_vec();
+        sorted.sort();
+
+        let mut skip_list = SkipList::default();
+        for n in &nums {
+            skip_list.insert(*n);
+        }
+
+        // select(n) returns the nth smallest element, matching a plain sorted Vec
+        for (n, v) in sorted.iter().enumerate() {
+            assert_eq!(skip_list.select(n), Some(v));
+        }
+        assert_eq!(skip_list.select(sorted.len()), None);
+
+        // rank(v) is the count of elements strictly less than v, whether or not v itself
+        // is present
+        for (n, v) in sorted.iter().enumerate() {
+            assert_eq!(skip_list.rank(v), n);
+        }
+        assert_eq!(skip_list.rank(&0), 0);
+        assert_eq!(skip_list.rank(&100), sorted.len());
+
+        // rank/select keep matching a plain sorted Vec across removals too
+        skip_list.remove(7);
+        skip_list.remove(1);
+        let sorted: Vec<i64> = sorted.into_iter().filter(|v| *v != 7 && *v != 1).collect();
+        for (n, v) in sorted.iter().enumerate() {
+            assert_eq!(skip_list.select(n), Some(v));
+        }
+        for (n, v) in sorted.iter().enumerate() {
+            assert_eq!(skip_list.rank(v), n);
+        }
+    }
+
+    #[test]
+    fn iter_and_range() {
+        let nums: [i64; 10] = [3, 1, 9, 12, 11, 16, 99, 18, 7, 22];
+        let mut sorted = nums.to_vec();
+        sorted.sort();
+
+        let mut skip_list = SkipList::default();
+        for n in &nums {
+            skip_list.insert(*n);
+        }
+
+        // iter walks every value in sorted order
+        let collected: Vec<i64> = skip_list.iter().cloned().collect();
+        assert_eq!(collected, sorted);
+
+        // range yields only the values within [lo, hi], inclusive on both ends
+        let collected: Vec<i64> = skip_list.range(&7, &18).cloned().collect();
+        assert_eq!(collected, vec![7, 9, 11, 12, 16, 18]);
+
+        // a range with no matching values yields nothing, whether it falls between two
+        // elements or entirely off one end of the list
+        assert_eq!(skip_list.range(&4, &6).next(), None);
+        assert_eq!(skip_list.range(&100, &200).next(), None);
+
+        // a range covering the whole list matches iter
+        let collected: Vec<i64> = skip_list.range(&0, &100).cloned().collect();
+        assert_eq!(collected, sorted);
+    }
 }